@@ -9,6 +9,54 @@ use nkd_w4_prim as w4;
 
 pub const SCREEN_SIZE: u32 = w4::SCREEN_SIZE;
 
+const fn fabs(x: f32) -> f32 {
+    if x < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn lerp_u8(a: u8, b: u8, t: f32) -> u8 {
+    (lerp_f32(a as f32, b as f32, t) + 0.5) as u8
+}
+
+// `f32::powf` is a std/libm-only method, unavailable to this `no_std` crate,
+// so raising to an arbitrary power is approximated via the classic
+// IEEE-754 bit-manipulation trick (treating the exponent bits as a
+// fixed-point logarithm) instead. It is not exact, but it is continuous,
+// monotonic, and its own inverse under reciprocal exponents, which is all
+// `srgb_to_linear`/`linear_to_srgb` need from it.
+fn powf_approx(x: f32, p: f32) -> f32 {
+    let bits = x.to_bits() as i32;
+    let bits = (p * (bits as f32 - 1064866805.0) + 1064866805.0) as i32;
+    f32::from_bits(bits as u32)
+}
+
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        powf_approx((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * powf_approx(c, 1.0 / 2.4) - 0.055
+    };
+
+    (c * 255.0 + 0.5) as u8
+}
+
 pub struct Palette(PhantomData<()>);
 
 pub static PALETTE: Mutex<Palette> = Mutex::new(Palette(PhantomData));
@@ -62,7 +110,7 @@ impl ops::Index<PaletteColor> for Palette {
         // static mutex; therefore, this Palette instance is unique, meaning
         // it is safe to assume that the aliasing state of self is identical
         // to that of the primitive palette binding.
-        let palette = &unsafe { *w4::PALETTE };
+        let palette = unsafe { &*w4::PALETTE };
         let color = &palette[index];
 
         // `Color` is representationally transparent with u32, and maintains
@@ -80,7 +128,7 @@ impl ops::IndexMut<PaletteColor> for Palette {
         // static mutex; therefore, this Palette instance is unique, meaning
         // it is safe to assume that the aliasing state of self is identical
         // to that of the primitive palette binding.
-        let palette = &mut unsafe { *w4::PALETTE };
+        let palette = unsafe { &mut *w4::PALETTE };
         let color = &mut palette[index];
 
         // `Color` is representationally transparent with u32, and maintains
@@ -90,6 +138,121 @@ impl ops::IndexMut<PaletteColor> for Palette {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    Default,
+    GameBoy,
+    Ice,
+    Dusk,
+}
+
+impl Scheme {
+    pub const fn colors(self) -> [Color; 4] {
+        match self {
+            Scheme::Default => [
+                Color::rgb(0xe0, 0xf8, 0xcf),
+                Color::rgb(0x86, 0xc0, 0x6c),
+                Color::rgb(0x30, 0x68, 0x50),
+                Color::rgb(0x07, 0x18, 0x21),
+            ],
+            Scheme::GameBoy => [
+                Color::rgb(0x9b, 0xbc, 0x0f),
+                Color::rgb(0x8b, 0xac, 0x0f),
+                Color::rgb(0x30, 0x62, 0x30),
+                Color::rgb(0x0f, 0x38, 0x0f),
+            ],
+            Scheme::Ice => [
+                Color::rgb(0xe6, 0xf7, 0xff),
+                Color::rgb(0x9f, 0xd8, 0xef),
+                Color::rgb(0x4f, 0x91, 0xb8),
+                Color::rgb(0x17, 0x3a, 0x56),
+            ],
+            Scheme::Dusk => [
+                Color::rgb(0xff, 0xd9, 0xa0),
+                Color::rgb(0xd9, 0x7a, 0x8a),
+                Color::rgb(0x6a, 0x4a, 0x7c),
+                Color::rgb(0x1b, 0x14, 0x3c),
+            ],
+        }
+    }
+}
+
+impl Palette {
+    pub fn set_from_hex(&mut self, hex: [&str; 4]) -> Result<(), ParseColorError> {
+        use PaletteColor::*;
+
+        let colors = [
+            Color::from_hex(hex[0])?,
+            Color::from_hex(hex[1])?,
+            Color::from_hex(hex[2])?,
+            Color::from_hex(hex[3])?,
+        ];
+
+        self[Color1] = colors[0];
+        self[Color2] = colors[1];
+        self[Color3] = colors[2];
+        self[Color4] = colors[3];
+
+        Ok(())
+    }
+
+    pub fn apply_scheme(&mut self, scheme: Scheme) {
+        use PaletteColor::*;
+
+        let colors = scheme.colors();
+
+        self[Color1] = colors[0];
+        self[Color2] = colors[1];
+        self[Color3] = colors[2];
+        self[Color4] = colors[3];
+    }
+
+    pub fn snapshot(&self) -> [Color; 4] {
+        use PaletteColor::*;
+
+        [self[Color1], self[Color2], self[Color3], self[Color4]]
+    }
+
+    pub fn restore(&mut self, colors: [Color; 4]) {
+        use PaletteColor::*;
+
+        self[Color1] = colors[0];
+        self[Color2] = colors[1];
+        self[Color3] = colors[2];
+        self[Color4] = colors[3];
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorError {
+    InvalidLength,
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidLength => {
+                write!(f, "color hex string has an invalid length")
+            }
+            ParseColorError::InvalidDigit => {
+                write!(f, "color hex string contains a non-hex-digit character")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseColorError {}
+
+fn hex_nibble(digit: u8) -> Result<u8, ParseColorError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(ParseColorError::InvalidDigit),
+    }
+}
+
 #[doc(alias = "Colour")]
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -142,6 +305,201 @@ impl Color {
         self
     }
 
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let hex = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        match hex.len() {
+            3 => {
+                let mut digits = hex.bytes();
+
+                let red = hex_nibble(digits.next().unwrap())?;
+                let green = hex_nibble(digits.next().unwrap())?;
+                let blue = hex_nibble(digits.next().unwrap())?;
+
+                Ok(Self::rgb(red * 17, green * 17, blue * 17))
+            }
+            6 => {
+                let mut digits = hex.bytes();
+
+                let red_hi = hex_nibble(digits.next().unwrap())?;
+                let red_lo = hex_nibble(digits.next().unwrap())?;
+                let green_hi = hex_nibble(digits.next().unwrap())?;
+                let green_lo = hex_nibble(digits.next().unwrap())?;
+                let blue_hi = hex_nibble(digits.next().unwrap())?;
+                let blue_lo = hex_nibble(digits.next().unwrap())?;
+
+                Ok(Self::rgb(
+                    red_hi * 16 + red_lo,
+                    green_hi * 16 + green_lo,
+                    blue_hi * 16 + blue_lo,
+                ))
+            }
+            _ => Err(ParseColorError::InvalidLength),
+        }
+    }
+
+    pub const fn hsv(h: f32, s: f32, v: f32) -> Self {
+        let c = v * s;
+        let h60 = h / 60.0;
+        let x = c * (1.0 - fabs(h60 % 2.0 - 1.0));
+        let m = v - c;
+
+        let (r, g, b) = match h60 as i32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self::rgb(
+            ((r + m) * 255.0 + 0.5) as u8,
+            ((g + m) * 255.0 + 0.5) as u8,
+            ((b + m) * 255.0 + 0.5) as u8,
+        )
+    }
+
+    pub const fn hsl(h: f32, s: f32, l: f32) -> Self {
+        let c = (1.0 - fabs(2.0 * l - 1.0)) * s;
+        let h60 = h / 60.0;
+        let x = c * (1.0 - fabs(h60 % 2.0 - 1.0));
+        let m = l - c / 2.0;
+
+        let (r, g, b) = match h60 as i32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self::rgb(
+            ((r + m) * 255.0 + 0.5) as u8,
+            ((g + m) * 255.0 + 0.5) as u8,
+            ((b + m) * 255.0 + 0.5) as u8,
+        )
+    }
+
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        use ColorChannel::*;
+
+        let r = self[Red] as f32 / 255.0;
+        let g = self[Green] as f32 / 255.0;
+        let b = self[Blue] as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let v = max;
+        let s = if max > 0.0 { delta / max } else { 0.0 };
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * (((g - b) / delta) % 6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let h = if h < 0.0 { h + 360.0 } else { h };
+
+        (h, s, v)
+    }
+
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        use ColorChannel::*;
+
+        let r = self[Red] as f32 / 255.0;
+        let g = self[Green] as f32 / 255.0;
+        let b = self[Blue] as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let l = (max + min) / 2.0;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - fabs(2.0 * l - 1.0))
+        };
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * (((g - b) / delta) % 6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let h = if h < 0.0 { h + 360.0 } else { h };
+
+        (h, s, l)
+    }
+
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        use ColorChannel::*;
+
+        Self::rgb(
+            lerp_u8(self[Red], other[Red], t),
+            lerp_u8(self[Green], other[Green], t),
+            lerp_u8(self[Blue], other[Blue], t),
+        )
+    }
+
+    pub fn lerp_linear(self, other: Self, t: f32) -> Self {
+        use ColorChannel::*;
+
+        let red = lerp_f32(srgb_to_linear(self[Red]), srgb_to_linear(other[Red]), t);
+        let green = lerp_f32(srgb_to_linear(self[Green]), srgb_to_linear(other[Green]), t);
+        let blue = lerp_f32(srgb_to_linear(self[Blue]), srgb_to_linear(other[Blue]), t);
+
+        Self::rgb(
+            linear_to_srgb(red),
+            linear_to_srgb(green),
+            linear_to_srgb(blue),
+        )
+    }
+
+    pub fn lighten(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        let l = (l + amount * (1.0 - l)).clamp(0.0, 1.0);
+
+        Self::hsl(h, s, l)
+    }
+
+    pub fn darken(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        let l = (l - amount * l).clamp(0.0, 1.0);
+
+        Self::hsl(h, s, l)
+    }
+
+    pub fn saturate(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        let s = (s + amount * (1.0 - s)).clamp(0.0, 1.0);
+
+        Self::hsl(h, s, l)
+    }
+
+    pub fn desaturate(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        let s = (s - amount * s).clamp(0.0, 1.0);
+
+        Self::hsl(h, s, l)
+    }
+
     pub const BLACK: Color = Color::rgb(0, 0, 0);
     pub const SILVER: Color = Color::rgb(192, 192, 192);
     #[doc(alias = "GREY")]
@@ -286,6 +644,56 @@ impl Color {
     pub const YELLOW_GREEN: Color = Color::rgb(154, 205, 50);
 }
 
+pub struct Gradient<'a> {
+    stops: &'a [(f32, Color)],
+}
+
+impl<'a> Gradient<'a> {
+    pub const fn new(stops: &'a [(f32, Color)]) -> Self {
+        Gradient { stops }
+    }
+
+    pub fn get(&self, t: f32) -> Color {
+        let stops = self.stops;
+
+        let last = match stops.len() {
+            0 => return Color::BLACK,
+            n => n - 1,
+        };
+
+        if t <= stops[0].0 {
+            return stops[0].1;
+        }
+
+        if t >= stops[last].0 {
+            return stops[last].1;
+        }
+
+        for i in 0..last {
+            let (pos_a, color_a) = stops[i];
+            let (pos_b, color_b) = stops[i + 1];
+
+            if t >= pos_a && t <= pos_b {
+                let span = pos_b - pos_a;
+                let local_t = if span > 0.0 { (t - pos_a) / span } else { 0.0 };
+
+                return color_a.lerp(color_b, local_t);
+            }
+        }
+
+        stops[last].1
+    }
+
+    pub fn sample_into_palette(&self, palette: &mut Palette) {
+        use PaletteColor::*;
+
+        palette[Color1] = self.get(0.0);
+        palette[Color2] = self.get(1.0 / 3.0);
+        palette[Color3] = self.get(2.0 / 3.0);
+        palette[Color4] = self.get(1.0);
+    }
+}
+
 impl Default for Color {
     fn default() -> Self {
         Color::BLACK
@@ -368,9 +776,334 @@ impl From<Color> for u32 {
     }
 }
 
+impl ops::BitAnd for Color {
+    type Output = Color;
+
+    fn bitand(self, rhs: Color) -> Color {
+        Color(self.0 & rhs.0)
+    }
+}
+
+impl ops::BitAnd<u32> for Color {
+    type Output = Color;
+
+    fn bitand(self, rhs: u32) -> Color {
+        Color(self.0 & rhs)
+    }
+}
+
+impl ops::BitOr for Color {
+    type Output = Color;
+
+    fn bitor(self, rhs: Color) -> Color {
+        Color(self.0 | rhs.0)
+    }
+}
+
+impl ops::BitOr<u32> for Color {
+    type Output = Color;
+
+    fn bitor(self, rhs: u32) -> Color {
+        Color(self.0 | rhs)
+    }
+}
+
+impl ops::BitXor for Color {
+    type Output = Color;
+
+    fn bitxor(self, rhs: Color) -> Color {
+        Color(self.0 ^ rhs.0)
+    }
+}
+
+impl ops::BitXor<u32> for Color {
+    type Output = Color;
+
+    fn bitxor(self, rhs: u32) -> Color {
+        Color(self.0 ^ rhs)
+    }
+}
+
+impl ops::Add for Color {
+    type Output = Color;
+
+    fn add(self, rhs: Color) -> Color {
+        use ColorChannel::*;
+
+        Color::rgb(
+            self[Red].saturating_add(rhs[Red]),
+            self[Green].saturating_add(rhs[Green]),
+            self[Blue].saturating_add(rhs[Blue]),
+        )
+    }
+}
+
+impl ops::Add<u32> for Color {
+    type Output = Color;
+
+    fn add(self, rhs: u32) -> Color {
+        self + Color::from(rhs)
+    }
+}
+
+impl ops::Sub for Color {
+    type Output = Color;
+
+    fn sub(self, rhs: Color) -> Color {
+        use ColorChannel::*;
+
+        Color::rgb(
+            self[Red].saturating_sub(rhs[Red]),
+            self[Green].saturating_sub(rhs[Green]),
+            self[Blue].saturating_sub(rhs[Blue]),
+        )
+    }
+}
+
+impl ops::Sub<u32> for Color {
+    type Output = Color;
+
+    fn sub(self, rhs: u32) -> Color {
+        self - Color::from(rhs)
+    }
+}
+
+impl ops::Mul<f32> for Color {
+    type Output = Color;
+
+    fn mul(self, rhs: f32) -> Color {
+        use ColorChannel::*;
+
+        Color::rgb(
+            scale_channel(self[Red], rhs),
+            scale_channel(self[Green], rhs),
+            scale_channel(self[Blue], rhs),
+        )
+    }
+}
+
+fn scale_channel(channel: u8, factor: f32) -> u8 {
+    let value = channel as f32 * factor;
+
+    if value <= 0.0 {
+        0
+    } else if value >= 255.0 {
+        255
+    } else {
+        value as u8
+    }
+}
+
 #[doc(alias = "ColourChannel")]
 pub enum ColorChannel {
     Red,
     Green,
     Blue,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsv_primary_colors() {
+        assert_eq!(Color::hsv(0.0, 1.0, 1.0), Color::RED);
+        assert_eq!(Color::hsv(120.0, 1.0, 1.0), Color::LIME);
+        assert_eq!(Color::hsv(240.0, 1.0, 1.0), Color::BLUE);
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        let original = Color::rgb(200, 80, 40);
+        let (h, s, v) = original.to_hsv();
+
+        assert_eq!(Color::hsv(h, s, v), original);
+    }
+
+    #[test]
+    fn hsl_primary_colors() {
+        assert_eq!(Color::hsl(0.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::hsl(120.0, 1.0, 0.5), Color::LIME);
+        assert_eq!(Color::hsl(240.0, 1.0, 0.5), Color::BLUE);
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        let original = Color::rgb(200, 80, 40);
+        let (h, s, l) = original.to_hsl();
+
+        assert_eq!(Color::hsl(h, s, l), original);
+    }
+
+    #[test]
+    fn lerp_endpoints_and_midpoint() {
+        let a = Color::BLACK;
+        let b = Color::WHITE;
+
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), Color::rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn lerp_linear_endpoints() {
+        let a = Color::BLACK;
+        let b = Color::WHITE;
+
+        assert_eq!(a.lerp_linear(b, 0.0), a);
+        assert_eq!(a.lerp_linear(b, 1.0), b);
+    }
+
+    #[test]
+    fn gradient_endpoints_and_midpoint() {
+        let gradient = Gradient::new(&[(0.0, Color::BLACK), (1.0, Color::WHITE)]);
+
+        assert_eq!(gradient.get(0.0), Color::BLACK);
+        assert_eq!(gradient.get(1.0), Color::WHITE);
+        assert_eq!(gradient.get(0.5), Color::rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn gradient_clamps_outside_its_stops() {
+        let gradient = Gradient::new(&[(0.25, Color::RED), (0.75, Color::BLUE)]);
+
+        assert_eq!(gradient.get(-1.0), Color::RED);
+        assert_eq!(gradient.get(2.0), Color::BLUE);
+    }
+
+    #[test]
+    fn gradient_with_multiple_stops_picks_bracketing_pair() {
+        let gradient = Gradient::new(&[
+            (0.0, Color::BLACK),
+            (0.5, Color::RED),
+            (1.0, Color::WHITE),
+        ]);
+
+        assert_eq!(gradient.get(0.5), Color::RED);
+        assert_eq!(gradient.get(0.75), Color::RED.lerp(Color::WHITE, 0.5));
+    }
+
+    #[test]
+    fn lighten_and_darken_move_lightness_toward_the_extremes() {
+        let gray = Color::rgb(100, 100, 100);
+
+        let lighter = gray.lighten(0.5);
+        let darker = gray.darken(0.5);
+
+        assert!(lighter[ColorChannel::Red] > 100);
+        assert!(darker[ColorChannel::Red] < 100);
+
+        assert_eq!(Color::BLACK.lighten(1.0), Color::WHITE);
+        assert_eq!(Color::WHITE.darken(1.0), Color::BLACK);
+    }
+
+    #[test]
+    fn saturate_and_desaturate_move_saturation_toward_the_extremes() {
+        let dull_red = Color::rgb(150, 100, 100);
+
+        let (_, saturated_s, _) = dull_red.saturate(0.5).to_hsl();
+        let (_, original_s, _) = dull_red.to_hsl();
+        let (_, desaturated_s, _) = dull_red.desaturate(0.5).to_hsl();
+
+        assert!(saturated_s > original_s);
+        assert!(desaturated_s < original_s);
+
+        let (_, fully_desaturated_s, _) = dull_red.desaturate(1.0).to_hsl();
+        assert_eq!(fully_desaturated_s, 0.0);
+    }
+
+    #[test]
+    fn from_hex_accepts_rrggbb_rgb_and_prefixes() {
+        assert_eq!(Color::from_hex("#ff0000"), Ok(Color::RED));
+        assert_eq!(Color::from_hex("ff0000"), Ok(Color::RED));
+        assert_eq!(Color::from_hex("0xFF0000"), Ok(Color::RED));
+        assert_eq!(Color::from_hex("0Xff0000"), Ok(Color::RED));
+        assert_eq!(Color::from_hex("#f00"), Ok(Color::RED));
+        assert_eq!(Color::from_hex("F00"), Ok(Color::RED));
+    }
+
+    #[test]
+    fn from_hex_rejects_bad_length() {
+        assert_eq!(Color::from_hex("ff00"), Err(ParseColorError::InvalidLength));
+        assert_eq!(Color::from_hex(""), Err(ParseColorError::InvalidLength));
+    }
+
+    #[test]
+    fn from_hex_rejects_non_hex_digits() {
+        assert_eq!(
+            Color::from_hex("#gg0000"),
+            Err(ParseColorError::InvalidDigit)
+        );
+        // "€€" is two 3-byte UTF-8 characters, so this is 6 bytes long but
+        // does not fall on a char boundary at every 2-byte offset.
+        assert_eq!(Color::from_hex("€€"), Err(ParseColorError::InvalidDigit));
+    }
+
+    #[test]
+    fn default_scheme_matches_the_wasm4_default_palette() {
+        assert_eq!(
+            Scheme::Default.colors(),
+            [
+                Color::rgb(0xe0, 0xf8, 0xcf),
+                Color::rgb(0x86, 0xc0, 0x6c),
+                Color::rgb(0x30, 0x68, 0x50),
+                Color::rgb(0x07, 0x18, 0x21),
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_scheme_snapshot_and_restore_round_trip() {
+        use PaletteColor::*;
+
+        let mut palette = PALETTE.lock();
+        let original = palette.snapshot();
+
+        palette.apply_scheme(Scheme::GameBoy);
+
+        assert_eq!(palette.snapshot(), Scheme::GameBoy.colors());
+        assert_eq!(palette[Color1], Color::rgb(0x9b, 0xbc, 0x0f));
+        assert_eq!(palette[Color4], Color::rgb(0x0f, 0x38, 0x0f));
+
+        palette.restore(original);
+
+        assert_eq!(palette.snapshot(), original);
+    }
+
+    #[test]
+    fn bitwise_ops_operate_on_the_raw_u32_layout() {
+        assert_eq!(Color::WHITE & Color::RED, Color::RED);
+        assert_eq!(Color::BLACK | Color::RED, Color::RED);
+        assert_eq!(Color::RED ^ Color::RED, Color::BLACK);
+
+        assert_eq!(Color::WHITE & 0x00ff0000, Color::RED);
+        assert_eq!(Color::BLACK | 0x00ff0000, Color::RED);
+        assert_eq!(Color::RED ^ 0x00ff0000, Color::BLACK);
+    }
+
+    #[test]
+    fn add_saturates_each_channel_at_255() {
+        let color = Color::rgb(200, 200, 200) + Color::rgb(100, 100, 100);
+
+        assert_eq!(color, Color::WHITE);
+        assert_eq!(Color::WHITE + Color::WHITE, Color::WHITE);
+        assert_eq!(Color::rgb(200, 0, 0) + 0x00640000, Color::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn sub_saturates_each_channel_at_0() {
+        let color = Color::rgb(50, 50, 50) - Color::rgb(100, 100, 100);
+
+        assert_eq!(color, Color::BLACK);
+        assert_eq!(Color::BLACK - Color::WHITE, Color::BLACK);
+        assert_eq!(Color::rgb(50, 0, 0) - 0x00640000, Color::BLACK);
+    }
+
+    #[test]
+    fn mul_scales_and_clamps_each_channel() {
+        assert_eq!(Color::rgb(100, 100, 100) * 2.0, Color::rgb(200, 200, 200));
+        assert_eq!(Color::rgb(200, 200, 200) * 2.0, Color::WHITE);
+        assert_eq!(Color::WHITE * 0.0, Color::BLACK);
+        assert_eq!(Color::WHITE * -1.0, Color::BLACK);
+    }
+}